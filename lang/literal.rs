@@ -7,10 +7,31 @@ trait IsNan {{
 impl IsNan for i64 {{
 }}
 
+// Bit-exact comparison, so `0.0` and `-0.0` aren't equal and NaN payloads
+// (quiet vs. signaling) are distinguished. Integers just fall back to
+// `==`, since they have no sign-of-zero or NaN payload to lose.
+trait BitsEq {{
+    fn bits_eq(&self, other: &Self) -> bool;
+}}
+
+impl BitsEq for i64 {{
+    fn bits_eq(&self, other: &Self) -> bool {{
+        self == other
+    }}
+}}
+
+impl BitsEq for f64 {{
+    fn bits_eq(&self, other: &Self) -> bool {{
+        self.to_bits() == other.to_bits()
+    }}
+}}
+
 pub fn main() {{
     let x: {type} = {value};
     let expected: {type} = {expected};
-    if expected.is_nan() {{
+    if {bits_exact} {{
+        assert!(x.bits_eq(&expected));
+    }} else if expected.is_nan() {{
         assert_eq!(x.is_nan(), expected.is_nan());
     }} else {{
         assert_eq!(x, expected);
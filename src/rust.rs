@@ -1,3 +1,44 @@
+// Strips a known numeric suffix (e.g. `f64`, `u32`) off a literal string,
+// returning the digits and the matched suffix. Returns `Err(())` if no
+// suffix in `suffixes` matches, covering both an unknown suffix and a
+// suffix from the wrong numeric family (e.g. a float suffix on a value
+// parsed as an integer).
+fn split_suffix<'a>(s: &'a str, suffixes: &[&'a str]) -> Result<(&'a str, &'a str), ()> {
+    for suffix in suffixes {
+        if let Some(digits) = s.strip_suffix(suffix) {
+            return Ok((digits, suffix));
+        }
+    }
+    Err(())
+}
+
+// Parses a Go-style imaginary literal (a terminal `i` marker, e.g. `1.5i`,
+// `3i`) into a `(real, imag)` pair. A literal with no `i` marker is
+// wholly real; the `i` must be the last character, so it can't appear
+// before an exponent. The digits are parsed with `str::parse::<f64>()`, so
+// hexadecimal forms such as `0x1p3i` are rejected.
+fn parse_imaginary(s: &str) -> Result<(f64, f64), ()> {
+    match s.strip_suffix('i') {
+        Some(digits) if digits.is_empty() => Err(()),
+        Some(digits) => digits.parse().map(|imag| (0.0, imag)).map_err(|_| ()),
+        None => s.parse().map(|real| (real, 0.0)).map_err(|_| ()),
+    }
+}
+
+// Checks that digit separators partition `digits` into uniform-size groups
+// of `group_size`, validated right-to-left from the radix point (the
+// leading, most-significant group may be shorter, but never longer).
+fn uniform_grouping(digits: &str, group_size: usize) -> bool {
+    let groups: Vec<&str> = digits.split('_').collect();
+    groups.iter().rev().enumerate().all(|(i, group)| {
+        if i == groups.len() - 1 {
+            !group.is_empty() && group.len() <= group_size
+        } else {
+            group.len() == group_size
+        }
+    })
+}
+
 pub fn main() {
     // DECIMAL - LITERAL
     // -----------------
@@ -159,6 +200,105 @@ pub fn main() {
         assert_eq!(r.is_ok(), *s);
     }
 
+    // DECIMAL - SUFFIXES
+    // ------------------
+
+    // FLAGS
+    // x/S = The format declares a set of permitted numeric suffixes
+    //       (`f32`/`f64` for floats, `i8`..`u128` for integers).
+
+    // suffixes - float
+    let float_suffixes = ["f32", "f64"];
+    let x: &[(&str, f64, bool)] = &[
+        ("1.956e2f64", 195.6e0, true),
+        ("135e12f32", 135e12, true),
+        ("1.0f64", 1.0, true),
+        ("1_000.5f64", 1_000.5, true),
+        ("1.0q8", 0.0, false), // fails, `q8` is not a known suffix
+    ];
+    for (s, expected, valid) in x {
+        match split_suffix(s, &float_suffixes) {
+            Ok((digits, suffix)) => {
+                assert!(*valid);
+                assert!(float_suffixes.contains(&suffix));
+                // str::parse rejects digit separators, so strip them first.
+                let value: f64 = digits.replace('_', "").parse().unwrap();
+                assert_eq!(value, *expected);
+            }
+            Err(()) => assert!(!*valid),
+        }
+    }
+
+    // suffixes - integer
+    let int_suffixes = [
+        "i8", "i16", "i32", "i64", "i128", "u8", "u16", "u32", "u64", "u128",
+    ];
+    let x: &[(&str, i64, bool)] = &[
+        ("1_000u32", 1_000, true),
+        ("42i64", 42, true),
+        ("7u8", 7, true),
+        ("10f64", 0, false),    // fails, float suffix on an integer literal
+        ("5u256", 0, false),    // fails, `u256` is not a known integer suffix
+    ];
+    for (s, expected, valid) in x {
+        match split_suffix(s, &int_suffixes) {
+            Ok((digits, suffix)) => {
+                assert!(*valid);
+                assert!(int_suffixes.contains(&suffix));
+                // str::parse rejects digit separators, so strip them first.
+                let value: i64 = digits.replace('_', "").parse().unwrap();
+                assert_eq!(value, *expected);
+            }
+            Err(()) => assert!(!*valid),
+        }
+    }
+
+    // DECIMAL - IMAGINARY
+    // -------------------
+
+    // FLAGS
+    // i/S = The format accepts a terminal `i` marking an imaginary literal
+    //       (Go-style, e.g. `1.5i`, `3i`), parsed as a (real, imag) pair.
+
+    // imaginary - float
+    let x: &[(&str, (f64, f64), bool)] = &[
+        ("1.5i", (0.0, 1.5), true),
+        ("3i", (0.0, 3.0), true),
+        ("1.0e3i", (0.0, 1.0e3), true),
+        ("1.0", (1.0, 0.0), true),
+        ("i", (0.0, 0.0), false),    // fails, bare `i` with no digits
+        ("1ie3", (0.0, 0.0), false), // fails, `i` must be terminal, not before the exponent
+    ];
+    for (s, expected, valid) in x {
+        match parse_imaginary(s) {
+            Ok(value) => {
+                assert!(*valid);
+                assert_eq!(value, *expected);
+            }
+            Err(()) => assert!(!*valid),
+        }
+    }
+
+    // DECIMAL - GROUPING
+    // ------------------
+
+    // FLAGS
+    // G/S = Digit separators must partition each component into
+    //       uniform-size groups (here, groups of 3), validated
+    //       right-to-left from the radix point.
+
+    // grouping - integer
+    let x: &[(&str, bool)] = &[
+        ("1_000_000", true),
+        ("1_1", false), // fails, the trailing group has only 1 digit
+        ("100", true),
+        ("10_00_000", false), // fails, the middle group has only 2 digits
+        ("1_00_0000", false), // fails, the trailing group has 4 digits
+    ];
+    for (digits, valid) in x {
+        assert_eq!(uniform_grouping(digits, 3), *valid);
+    }
+
     // HEX - LITERAL
     // -------------
 
@@ -167,13 +307,19 @@ pub fn main() {
     // +/M = No mantissa positive sign.
     // p/I = The format supports parsing integers.
     // w/I = The format supports writing integers.
+    // p/F = The format supports parsing floats.
+    // w/F = The format supports writing floats.
     // e/P = Case-sensitive base prefix.
     // r/P = Require base prefixes.
+    // B/E = Exponent base is a power of two (`p`/`P`), decoupled from the
+    //       base-16 mantissa radix.
+    // P/R = Required `p` exponent when the literal has a fraction.
     //
     // DIGIT SEPARATORS
     // I/I = Integer internal digit separator.
     // F/I = Fraction internal digit separator.
     // E/I = Exponent internal digit separator.
+    // E/L = Exponent leading digit separator.
     // I/T = Integer trailing digit separator.
     // F/T = Fraction trailing digit separator.
     // E/T = Exponent trailing digit separator.
@@ -182,9 +328,15 @@ pub fn main() {
     // E/C = Exponent consecutive digit separator.
 
     // literals - float
-    // all hexadecimal floats are not supported
+    // mantissa digits are base-16, the fractional digit at position `n`
+    // contributes `digit * 16^(-n)`, and the required `p`/`P` exponent is a
+    // power of two, e.g. 0x1.8p3 == (1 + 8/16) * 2^3 == 12.0 -- rustc's own
+    // literal grammar has no hexadecimal float support, so every case here
+    // is commented out; see HEX - STRINGS for the runtime-parsed form.
     let x: &[f64] = &[
-        //0x1.0,    // fails
+        //0x1p3,      // fails, hexadecimal float literals are not supported
+        //0x1.8p3,    // fails, hexadecimal float literals are not supported
+        //0x1.0,      // fails, fraction requires a `p` exponent
     ];
     for i in x {
         println!("{i:?}");
@@ -208,6 +360,62 @@ pub fn main() {
         println!("{i:?}");
     }
 
+    // HEX - STRINGS
+    // -------------
+
+    // FLAGS
+    // p/F = The format supports parsing floats.
+    // B/E = Exponent base is a power of two (`p`/`P`), decoupled from the
+    //       base-16 mantissa radix.
+    // P/R = Required `p` exponent when the literal has a fraction.
+
+    // string - floats
+    // `f64::from_str` has no hexadecimal float support either, so every
+    // case here -- valid per the semantics above or not -- fails to parse
+    // against std. A format that enables `p/F` needs an extended parser
+    // (e.g. lexical's) to actually accept these.
+    let x: &[(&str, bool)] = &[
+        ("0x1p3", false),
+        ("0x1.8p3", false),
+        ("0x1.0p0", false),
+        ("0xA.8p1", false),
+        ("0x1p-3", false),
+        ("0x1P3", false),
+        ("0x1.0", false),   // fails, fraction requires a `p` exponent
+        ("0x1.8e3", false), // fails, `e` is a decimal exponent, not `p`
+        ("1.8p3", false),   // fails, missing the `0x` prefix
+    ];
+    for (i, s) in x {
+        let r = i.parse::<f64>();
+        println!("{i:#?}, {r:?}");
+        assert_eq!(r.is_ok(), *s);
+    }
+
+    // NOTE No HEX - IMAGINARY block: `parse_imaginary` strips the `i` and
+    // hands the remaining digits to `str::parse::<f64>()`, which (per
+    // HEX - STRINGS above) cannot parse hexadecimal floats at all, so
+    // there's no real case to assert here until an extended parser backs
+    // the `p/F` flag.
+
+    // HEX - GROUPING
+    // --------------
+
+    // FLAGS
+    // G/S = Digit separators must partition each component into
+    //       uniform-size groups (here, groups of 4), validated
+    //       right-to-left from the radix point.
+
+    // grouping - integer
+    let x: &[(&str, bool)] = &[
+        ("dead_beef", true),
+        ("dead", true),
+        ("d_eadbeef", false), // fails, the leading group has 1 digit but the trailing group has 7
+        ("dea_dbeef", false), // fails, the leading group has 3 digits and the trailing group has 5
+    ];
+    for (digits, valid) in x {
+        assert_eq!(uniform_grouping(digits, 4), *valid);
+    }
+
     // BINARY - LITERAL
     // ----------------
 
@@ -216,13 +424,19 @@ pub fn main() {
     // +/M = No mantissa positive sign.
     // p/I = The format supports parsing integers.
     // w/I = The format supports writing integers.
+    // p/F = The format supports parsing floats.
+    // w/F = The format supports writing floats.
     // e/P = Case-sensitive base prefix.
     // r/P = Require base prefixes.
+    // B/E = Exponent base is a power of two (`p`/`P`), decoupled from the
+    //       base-2 mantissa radix.
+    // P/R = Required `p` exponent when the literal has a fraction.
     //
     // DIGIT SEPARATORS
     // I/I = Integer internal digit separator.
     // F/I = Fraction internal digit separator.
     // E/I = Exponent internal digit separator.
+    // E/L = Exponent leading digit separator.
     // I/T = Integer trailing digit separator.
     // F/T = Fraction trailing digit separator.
     // E/T = Exponent trailing digit separator.
@@ -231,21 +445,28 @@ pub fn main() {
     // E/C = Exponent consecutive digit separator.
 
     // literals - float
-    // all binary floats are not supported
+    // mantissa digits are base-2, the fractional digit at position `n`
+    // contributes `digit * 2^(-n)`, and the required `p`/`P` exponent is a
+    // power of two, e.g. 0b1.01p2 == (1 + 1/4) * 2^2 == 5.0 -- rustc's own
+    // literal grammar has no binary float support, so every case here is
+    // commented out; see BINARY - STRINGS for the runtime-parsed form.
     let x: &[f64] = &[
-        //0x1.0,    // fails
+        //0b1p2,              // fails, binary float literals are not supported
+        //0b1.01p2,           // fails, binary float literals are not supported
+        //0b1_0.0_1p1_0,      // fails, binary float literals are not supported
+        //0b1.0,              // fails, fraction requires a `p` exponent
+        //0b1.2p0,            // fails, `2` is not a valid binary digit
     ];
     for i in x {
         println!("{i:?}");
     }
 
     // literals - integers
-    // all binary floats are not supported
     let x: &[i64] = &[
         //+0b1,    // fails
         -0b1,
         0b1,
-        0b01
+        0b01,
         0b0_1,
         0b0__1,
         0b_01,
@@ -258,6 +479,37 @@ pub fn main() {
         println!("{i:?}");
     }
 
+    // BINARY - STRINGS
+    // ----------------
+
+    // FLAGS
+    // p/F = The format supports parsing floats.
+    // B/E = Exponent base is a power of two (`p`/`P`), decoupled from the
+    //       base-2 mantissa radix.
+    // P/R = Required `p` exponent when the literal has a fraction.
+
+    // string - floats
+    // `f64::from_str` has no binary float support either, so every case
+    // here -- valid per the semantics above or not -- fails to parse
+    // against std. A format that enables `p/F` needs an extended parser
+    // (e.g. lexical's) to actually accept these.
+    let x: &[(&str, bool)] = &[
+        ("0b1p2", false),
+        ("0b1.01p2", false),
+        ("0b1.0p0", false),
+        ("0b1_0.0_1p1_0", false),
+        ("0b_1.01p2", false),
+        ("0b1.01p_2", false),
+        ("0b1.0", false),   // fails, fraction requires a `p` exponent
+        ("0b1.2p0", false), // fails, `2` is not a valid binary digit
+        ("1.01p2", false),  // fails, missing the `0b` prefix
+    ];
+    for (i, s) in x {
+        let r = i.parse::<f64>();
+        println!("{i:#?}, {r:?}");
+        assert_eq!(r.is_ok(), *s);
+    }
+
     // OCTAL - LITERAL
     // ---------------
 
@@ -266,13 +518,19 @@ pub fn main() {
     // +/M = No mantissa positive sign.
     // p/I = The format supports parsing integers.
     // w/I = The format supports writing integers.
+    // p/F = The format supports parsing floats.
+    // w/F = The format supports writing floats.
     // e/P = Case-sensitive base prefix.
     // r/P = Require base prefixes.
+    // B/E = Exponent base is a power of two (`p`/`P`), decoupled from the
+    //       base-8 mantissa radix.
+    // P/R = Required `p` exponent when the literal has a fraction.
     //
     // DIGIT SEPARATORS
     // I/I = Integer internal digit separator.
     // F/I = Fraction internal digit separator.
     // E/I = Exponent internal digit separator.
+    // E/L = Exponent leading digit separator.
     // I/T = Integer trailing digit separator.
     // F/T = Fraction trailing digit separator.
     // E/T = Exponent trailing digit separator.
@@ -281,9 +539,17 @@ pub fn main() {
     // E/C = Exponent consecutive digit separator.
 
     // literals - float
-    // all binary floats are not supported
+    // mantissa digits are base-8, the fractional digit at position `n`
+    // contributes `digit * 8^(-n)`, and the required `p`/`P` exponent is a
+    // power of two, e.g. 0o1.4p1 == (1 + 4/8) * 2^1 == 3.0 -- rustc's own
+    // literal grammar has no octal float support, so every case here is
+    // commented out; see OCTAL - STRINGS for the runtime-parsed form.
     let x: &[f64] = &[
-        //0o1.0,    // fails
+        //0o1p1,              // fails, octal float literals are not supported
+        //0o1.4p1,            // fails, octal float literals are not supported
+        //0o1_0.0_4p1_0,      // fails, octal float literals are not supported
+        //0o1.0,              // fails, fraction requires a `p` exponent
+        //0o1.8p0,            // fails, `8` is not a valid octal digit
     ];
     for i in x {
         println!("{i:?}");
@@ -308,10 +574,43 @@ pub fn main() {
         println!("{i:?}");
     }
 
-    // NOTE That the string hex, binary, and octal values
+    // OCTAL - STRINGS
+    // ---------------
+
+    // FLAGS
+    // p/F = The format supports parsing floats.
+    // B/E = Exponent base is a power of two (`p`/`P`), decoupled from the
+    //       base-8 mantissa radix.
+    // P/R = Required `p` exponent when the literal has a fraction.
+
+    // string - floats
+    // `f64::from_str` has no octal float support either, so every case
+    // here -- valid per the semantics above or not -- fails to parse
+    // against std. A format that enables `p/F` needs an extended parser
+    // (e.g. lexical's) to actually accept these.
+    let x: &[(&str, bool)] = &[
+        ("0o1p1", false),
+        ("0o1.4p1", false),
+        ("0o1.0p0", false),
+        ("0o1_0.0_4p1_0", false),
+        ("0o_1.4p1", false),
+        ("0o1.4p_1", false),
+        ("0o1.0", false),   // fails, fraction requires a `p` exponent
+        ("0o1.8p0", false), // fails, `8` is not a valid octal digit
+        ("1.4p1", false),   // fails, missing the `0o` prefix
+    ];
+    for (i, s) in x {
+        let r = i.parse::<f64>();
+        println!("{i:#?}, {r:?}");
+        assert_eq!(r.is_ok(), *s);
+    }
+
+    // NOTE That the string hex, binary, and octal integer values
     // are all basic formats, only support writing to, and
     // they alternate betweem required base prefixes and not
-    // through the formatting API so we skip those.
+    // through the formatting API so we skip those. The float
+    // formats for these bases support parsing (see `p/F` above),
+    // so they get their own STRINGS table instead.
     //
     // They can easy be built via the formatting API.
 }